@@ -30,6 +30,12 @@ pub enum Error {
         source: serde_mgpk::encode::Error,
     },
 
+    #[error("MessagePack Deserialization error")]
+    MsgPackDeserializationError {
+        #[from]
+        source: serde_mgpk::decode::Error,
+    },
+
     #[error("DFS Serialization error")]
     DFSSerializationError {
         #[from]
@@ -59,4 +65,10 @@ pub enum Error {
 
     #[error("Improper Prefix Type")]
     ImproperPrefixType,
+
+    #[error("Error parsing version string: {0}")]
+    VersionParsingError(String),
+
+    #[error("Incorrect size: expected {expected}, got {actual}")]
+    IncorrectSize { expected: usize, actual: usize },
 }
@@ -0,0 +1,43 @@
+use crate::{
+    error::Error,
+    event::sections::{InceptionWitnessConfig, KeyConfig},
+    prefix::{IdentifierPrefix, SelfAddressingPrefix},
+    state::IdentifierState,
+};
+use serde::{Deserialize, Serialize};
+
+/// The `icp` event: establishes an identifier's initial signing keys,
+/// witness pool and next-key commitment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InceptionEvent {
+    #[serde(flatten)]
+    pub key_config: KeyConfig,
+
+    #[serde(flatten)]
+    pub witness_config: InceptionWitnessConfig,
+
+    #[serde(rename = "c")]
+    pub inception_configuration: Vec<String>,
+}
+
+impl InceptionEvent {
+    /// Establishes `prefix` at sequence number `sn`, seeding its key state
+    /// straight from this event's own sections.
+    pub fn apply_to(
+        &self,
+        _state: IdentifierState,
+        prefix: IdentifierPrefix,
+        sn: u64,
+    ) -> Result<IdentifierState, Error> {
+        Ok(IdentifierState {
+            prefix,
+            sn,
+            last: SelfAddressingPrefix::default(),
+            current: self.key_config.clone(),
+            next: self.key_config.threshold_key_digest.clone(),
+            witnesses: self.witness_config.witnesses.clone(),
+            tally: self.witness_config.tally,
+            delegated_keys: vec![],
+        })
+    }
+}
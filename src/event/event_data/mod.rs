@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+pub mod inception;
+
+use inception::InceptionEvent;
+
+/// The type-specific payload of an [`super::Event`], tagged on the wire by
+/// its `t` (ilk) field.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "t", rename_all = "lowercase")]
+pub enum EventData {
+    Icp(InceptionEvent),
+}
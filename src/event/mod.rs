@@ -0,0 +1,42 @@
+use crate::{
+    error::Error,
+    event_message::{serialization_format::SerializationFormat, EventMessage},
+    prefix::{AttachedSignaturePrefix, IdentifierPrefix},
+    state::{EventSemantics, IdentifierState},
+};
+use event_data::EventData;
+use serde::{Deserialize, Serialize};
+use serde_hex::{Compact, SerHex};
+
+pub mod event_data;
+pub mod sections;
+
+/// The common envelope shared by every event in a KEL: who it belongs to,
+/// its sequence number, and its type-specific payload.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Event {
+    #[serde(rename = "i")]
+    pub prefix: IdentifierPrefix,
+
+    #[serde(rename = "s", with = "SerHex::<Compact>")]
+    pub sn: u64,
+
+    #[serde(flatten)]
+    pub event_data: EventData,
+}
+
+impl Event {
+    /// Frames this event as a JSON-serialized [`EventMessage`] with `sigs`
+    /// attached.
+    pub fn sign(&self, sigs: Vec<AttachedSignaturePrefix>) -> Result<EventMessage, Error> {
+        EventMessage::new(self, SerializationFormat::JSON, sigs)
+    }
+}
+
+impl EventSemantics for Event {
+    fn apply_to(&self, state: IdentifierState) -> Result<IdentifierState, Error> {
+        match &self.event_data {
+            EventData::Icp(icp) => icp.apply_to(state, self.prefix.clone(), self.sn),
+        }
+    }
+}
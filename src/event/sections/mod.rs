@@ -0,0 +1,34 @@
+use crate::prefix::{BasicPrefix, SelfAddressingPrefix};
+use serde::{Deserialize, Serialize};
+
+pub mod seal;
+pub mod threshold;
+
+use threshold::SigningThreshold;
+
+/// The signing configuration of an identifier at a point in its KEL: the
+/// keys that may sign on its behalf and the threshold they must meet.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct KeyConfig {
+    #[serde(rename = "kt")]
+    pub threshold: SigningThreshold,
+
+    /// Formerly `public_keys`; renamed to match [`SigningThreshold`]'s
+    /// weighted clauses, which align positionally with this list.
+    #[serde(rename = "k")]
+    pub signers: Vec<BasicPrefix>,
+
+    #[serde(rename = "n")]
+    pub threshold_key_digest: SelfAddressingPrefix,
+}
+
+/// The witness pool committed to by an inception event: the witnesses
+/// themselves and the threshold of them required to accept a rotation.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct InceptionWitnessConfig {
+    #[serde(rename = "bt")]
+    pub tally: u64,
+
+    #[serde(rename = "b")]
+    pub witnesses: Vec<BasicPrefix>,
+}
@@ -1,6 +1,14 @@
-use crate::prefix::{IdentifierPrefix, SelfAddressingPrefix};
+use crate::{
+    derivation::{
+        blake2b_256_digest, blake2s_256_digest, blake3_256_digest, sha2_256_digest,
+        sha2_512_digest, sha3_256_digest, sha3_512_digest,
+    },
+    error::Error,
+    prefix::{IdentifierPrefix, SelfAddressingPrefix},
+};
 use serde::{Deserialize, Serialize};
 use serde_hex::{Compact, SerHex};
+use std::mem::discriminant;
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(tag = "seal", rename_all = "lowercase")]
 pub enum Seal {
@@ -22,6 +30,174 @@ pub struct RootSeal {
     pub tree_root: SelfAddressingPrefix,
 }
 
+/// A Merkle inclusion proof that `leaf` is committed under a [`RootSeal`]'s
+/// `tree_root`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MerkleProof {
+    pub leaf: SelfAddressingPrefix,
+    pub siblings: Vec<SelfAddressingPrefix>,
+    /// For each sibling, `false` if it sits to the left of the running hash
+    /// at that level, `true` if it sits to the right.
+    pub path: Vec<bool>,
+}
+
+impl RootSeal {
+    /// Verifies `proof` against this seal's `tree_root` by recomputing the
+    /// Merkle path bottom-up from `proof.leaf`, rehashing at each level with
+    /// the same self-addressing derivation code as `tree_root` (so SHA3-512
+    /// roots rehash with SHA3-512, etc). An empty `siblings` list means the
+    /// leaf must equal the root directly.
+    pub fn verify_inclusion(&self, proof: &MerkleProof) -> Result<bool, Error> {
+        if discriminant(&proof.leaf) != discriminant(&self.tree_root)
+            || proof
+                .siblings
+                .iter()
+                .any(|sibling| discriminant(sibling) != discriminant(&self.tree_root))
+        {
+            return Err(Error::ImproperPrefixType);
+        }
+
+        if proof.siblings.len() != proof.path.len() {
+            return Err(Error::SemanticError(
+                "Merkle proof siblings and path must be the same length".to_string(),
+            ));
+        }
+
+        if proof.siblings.is_empty() {
+            return Ok(proof.leaf == self.tree_root);
+        }
+
+        let mut running = digest_bytes(&proof.leaf).to_vec();
+        for (sibling, &sibling_on_right) in proof.siblings.iter().zip(proof.path.iter()) {
+            let sibling_bytes = digest_bytes(sibling);
+            let concatenated = if sibling_on_right {
+                [running.as_slice(), sibling_bytes].concat()
+            } else {
+                [sibling_bytes, running.as_slice()].concat()
+            };
+            running = rehash(&self.tree_root, &concatenated);
+        }
+
+        Ok(running == digest_bytes(&self.tree_root))
+    }
+}
+
+/// Extracts the raw digest bytes out of any `SelfAddressingPrefix` variant.
+fn digest_bytes(prefix: &SelfAddressingPrefix) -> &[u8] {
+    match prefix {
+        SelfAddressingPrefix::Blake3_256(d)
+        | SelfAddressingPrefix::Blake2B256(d)
+        | SelfAddressingPrefix::Blake2S256(d)
+        | SelfAddressingPrefix::SHA3_256(d)
+        | SelfAddressingPrefix::SHA3_512(d)
+        | SelfAddressingPrefix::SHA2_256(d)
+        | SelfAddressingPrefix::SHA2_512(d) => d,
+    }
+}
+
+/// Hashes `data` using the same derivation algorithm as `like`.
+fn rehash(like: &SelfAddressingPrefix, data: &[u8]) -> Vec<u8> {
+    match like {
+        SelfAddressingPrefix::Blake3_256(_) => blake3_256_digest(data),
+        SelfAddressingPrefix::Blake2B256(_) => blake2b_256_digest(data),
+        SelfAddressingPrefix::Blake2S256(_) => blake2s_256_digest(data),
+        SelfAddressingPrefix::SHA3_256(_) => sha3_256_digest(data),
+        SelfAddressingPrefix::SHA3_512(_) => sha3_512_digest(data),
+        SelfAddressingPrefix::SHA2_256(_) => sha2_256_digest(data),
+        SelfAddressingPrefix::SHA2_512(_) => sha2_512_digest(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(byte: u8) -> SelfAddressingPrefix {
+        SelfAddressingPrefix::SHA3_256(vec![byte; 32])
+    }
+
+    #[test]
+    fn verifies_a_two_level_inclusion_proof() {
+        let a = leaf(1);
+        let b = leaf(2);
+        let c = leaf(3);
+        let d = leaf(4);
+
+        let ab = SelfAddressingPrefix::SHA3_256(rehash(
+            &a,
+            &[digest_bytes(&a), digest_bytes(&b)].concat(),
+        ));
+        let cd = SelfAddressingPrefix::SHA3_256(rehash(
+            &a,
+            &[digest_bytes(&c), digest_bytes(&d)].concat(),
+        ));
+        let root = SelfAddressingPrefix::SHA3_256(rehash(
+            &a,
+            &[digest_bytes(&ab), digest_bytes(&cd)].concat(),
+        ));
+        let seal = RootSeal { tree_root: root };
+
+        let proof = MerkleProof {
+            leaf: a,
+            siblings: vec![b, cd],
+            path: vec![true, true],
+        };
+
+        assert!(seal.verify_inclusion(&proof).unwrap());
+    }
+
+    #[test]
+    fn empty_siblings_requires_leaf_to_equal_root() {
+        let root = leaf(9);
+        let seal = RootSeal {
+            tree_root: root.clone(),
+        };
+
+        let matching = MerkleProof {
+            leaf: root,
+            siblings: vec![],
+            path: vec![],
+        };
+        assert!(seal.verify_inclusion(&matching).unwrap());
+
+        let mismatched = MerkleProof {
+            leaf: leaf(10),
+            siblings: vec![],
+            path: vec![],
+        };
+        assert!(!seal.verify_inclusion(&mismatched).unwrap());
+    }
+
+    #[test]
+    fn rejects_mismatched_derivation_codes() {
+        let seal = RootSeal {
+            tree_root: SelfAddressingPrefix::SHA3_256(vec![0; 32]),
+        };
+        let proof = MerkleProof {
+            leaf: SelfAddressingPrefix::SHA3_512(vec![0; 64]),
+            siblings: vec![],
+            path: vec![],
+        };
+        assert!(matches!(
+            seal.verify_inclusion(&proof),
+            Err(Error::ImproperPrefixType)
+        ));
+    }
+
+    #[test]
+    fn rejects_siblings_and_path_length_mismatch() {
+        let seal = RootSeal {
+            tree_root: leaf(1),
+        };
+        let proof = MerkleProof {
+            leaf: leaf(2),
+            siblings: vec![leaf(3), leaf(4)],
+            path: vec![true],
+        };
+        assert!(seal.verify_inclusion(&proof).is_err());
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EventSeal {
     #[serde(rename = "pre")]
@@ -0,0 +1,106 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A signing threshold over the current keys of an identifier.
+///
+/// `Unweighted` is a plain `M-of-N` count, the original behavior.
+/// `Weighted` is a list of AND-ed clauses; each clause is a list of
+/// `(numerator, denominator)` weights positionally aligned with
+/// `KeyConfig::signers` (formerly `public_keys`). A clause is satisfied
+/// when the weights of its validly-signing indices sum to at least one.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SigningThreshold {
+    Unweighted(u64),
+    Weighted(Vec<Vec<(u64, u64)>>),
+}
+
+impl Default for SigningThreshold {
+    fn default() -> Self {
+        Self::Unweighted(0)
+    }
+}
+
+impl SigningThreshold {
+    /// Returns whether `signing_indices` (the positions of the keys whose
+    /// attached signatures verified) satisfy this threshold.
+    ///
+    /// `signing_indices` is deduplicated before counting/summing, so
+    /// attaching the same signer's signature more than once can't be used
+    /// to inflate a plain count or a weighted clause's sum.
+    pub fn is_satisfied_by(&self, signing_indices: &[usize]) -> Result<bool, Error> {
+        let unique_indices: BTreeSet<usize> = signing_indices.iter().copied().collect();
+        match self {
+            Self::Unweighted(threshold) => Ok(unique_indices.len() as u64 >= *threshold),
+            Self::Weighted(clauses) => Ok(clauses
+                .iter()
+                .all(|clause| Self::clause_satisfied(clause, &unique_indices))),
+        }
+    }
+
+    /// Sums the exact rational weights of `signing_indices` within `clause`
+    /// via integer cross-multiplication (no floats) and checks the sum is
+    /// at least one. An index absent from the clause contributes nothing;
+    /// an empty clause can never be satisfied.
+    fn clause_satisfied(clause: &[(u64, u64)], signing_indices: &BTreeSet<usize>) -> bool {
+        let mut num_acc: u128 = 0;
+        let mut den_acc: u128 = 1;
+        for &idx in signing_indices {
+            if let Some(&(numerator, denominator)) = clause.get(idx) {
+                if denominator == 0 {
+                    continue;
+                }
+                let (numerator, denominator) = (numerator as u128, denominator as u128);
+                num_acc = num_acc * denominator + numerator * den_acc;
+                den_acc *= denominator;
+            }
+        }
+        num_acc >= den_acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unweighted_is_a_plain_count() {
+        let threshold = SigningThreshold::Unweighted(2);
+        assert!(!threshold.is_satisfied_by(&[0]).unwrap());
+        assert!(threshold.is_satisfied_by(&[0, 1]).unwrap());
+    }
+
+    #[test]
+    fn weighted_clause_sums_rational_weights() {
+        // Two keys each weighted 1/2: either alone is not enough, both together is.
+        let threshold = SigningThreshold::Weighted(vec![vec![(1, 2), (1, 2)]]);
+        assert!(!threshold.is_satisfied_by(&[0]).unwrap());
+        assert!(threshold.is_satisfied_by(&[0, 1]).unwrap());
+    }
+
+    #[test]
+    fn weighted_clauses_are_and_ed() {
+        // Two clauses, each fully weighted to a distinct index: both
+        // signers must sign, one alone is not enough.
+        let threshold = SigningThreshold::Weighted(vec![vec![(1, 1)], vec![(0, 1), (1, 1)]]);
+        assert!(!threshold.is_satisfied_by(&[0]).unwrap());
+        assert!(threshold.is_satisfied_by(&[0, 1]).unwrap());
+    }
+
+    #[test]
+    fn empty_clause_is_unsatisfiable() {
+        let threshold = SigningThreshold::Weighted(vec![vec![]]);
+        assert!(!threshold.is_satisfied_by(&[0, 1, 2]).unwrap());
+    }
+
+    #[test]
+    fn replaying_the_same_signature_does_not_inflate_the_count() {
+        // Same index attached "twice" must not count as two distinct signers.
+        let unweighted = SigningThreshold::Unweighted(2);
+        assert!(!unweighted.is_satisfied_by(&[0, 0]).unwrap());
+
+        let weighted = SigningThreshold::Weighted(vec![vec![(1, 2), (1, 2)]]);
+        assert!(!weighted.is_satisfied_by(&[0, 0]).unwrap());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    error::Error,
+    prefix::{attached_signature::get_sig_count, AttachedSignaturePrefix},
+};
+use core::str::FromStr;
+
+const COUNT_CODE_WIDTH: usize = 4;
+const B64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn b64_index(c: u8) -> Result<u32, Error> {
+    B64_CHARS
+        .iter()
+        .position(|&b| b == c)
+        .map(|p| p as u32)
+        .ok_or(Error::DeserializationError)
+}
+
+/// Inverse of [`get_sig_count`]: recovers the number of signatures in the
+/// group from its `"-A##"` count code.
+fn decode_sig_count(count_code: &[u8]) -> Result<usize, Error> {
+    if count_code.len() != COUNT_CODE_WIDTH || count_code[0] != b'-' || count_code[1] != b'A' {
+        return Err(Error::DeserializationError);
+    }
+    let hi = b64_index(count_code[2])?;
+    let lo = b64_index(count_code[3])?;
+    Ok(((hi << 6) | lo) as usize)
+}
+
+/// Fixed text width (derivation code + material) of one attached
+/// signature's CESR representation, keyed by its leading selector byte.
+/// Only `Ed25519Sha512` is known to this crate today; extend this table
+/// alongside new `SelfSigningPrefix` variants.
+fn attached_signature_width(selector: u8) -> Result<usize, Error> {
+    match selector {
+        b'A' => Ok(88),
+        _ => Err(Error::DeserializationError),
+    }
+}
+
+/// Parses a count code followed by that many fixed-width attached
+/// signatures out of a contiguous byte stream, the way they're framed
+/// after an event body regardless of its serialization.
+///
+/// Returns the parsed signatures and whatever bytes remain after the
+/// group (e.g. the next message in a KEL stream).
+pub fn parse_attachments(bytes: &[u8]) -> Result<(Vec<AttachedSignaturePrefix>, &[u8]), Error> {
+    if bytes.len() < COUNT_CODE_WIDTH {
+        return Err(Error::DeserializationError);
+    }
+    let (count_code, mut rest) = bytes.split_at(COUNT_CODE_WIDTH);
+    let count = decode_sig_count(count_code)?;
+
+    let mut sigs = Vec::with_capacity(count);
+    for _ in 0..count {
+        let selector = *rest.first().ok_or(Error::DeserializationError)?;
+        let width = attached_signature_width(selector)?;
+        if rest.len() < width {
+            return Err(Error::DeserializationError);
+        }
+        let (sig_bytes, tail) = rest.split_at(width);
+        let sig_str = std::str::from_utf8(sig_bytes).map_err(|_| Error::DeserializationError)?;
+        sigs.push(AttachedSignaturePrefix::from_str(sig_str)?);
+        rest = tail;
+    }
+
+    Ok((sigs, rest))
+}
+
+/// Encodes a count code followed by `sigs` as a contiguous byte stream,
+/// the inverse of [`parse_attachments`].
+pub fn encode_attachments(sigs: &[AttachedSignaturePrefix]) -> Vec<u8> {
+    let mut out = get_sig_count(sigs.len() as u16).into_bytes();
+    for sig in sigs {
+        out.extend(sig.to_str().into_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prefix::SelfSigningPrefix;
+
+    fn sig(index: u16, byte: u8) -> AttachedSignaturePrefix {
+        AttachedSignaturePrefix {
+            index,
+            sig: SelfSigningPrefix::Ed25519Sha512(vec![byte; 64]),
+        }
+    }
+
+    #[test]
+    fn round_trips_an_empty_group() {
+        let encoded = encode_attachments(&[]);
+        let (sigs, rest) = parse_attachments(&encoded).unwrap();
+        assert!(sigs.is_empty());
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn round_trips_several_signatures() {
+        let sigs = vec![sig(0, 1), sig(1, 2), sig(2, 3)];
+        let encoded = encode_attachments(&sigs);
+        let (parsed, rest) = parse_attachments(&encoded).unwrap();
+        assert_eq!(parsed, sigs);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn leaves_trailing_bytes_after_the_group_untouched() {
+        let sigs = vec![sig(0, 9)];
+        let mut encoded = encode_attachments(&sigs);
+        encoded.extend_from_slice(b"next message");
+        let (parsed, rest) = parse_attachments(&encoded).unwrap();
+        assert_eq!(parsed, sigs);
+        assert_eq!(rest, b"next message");
+    }
+
+    #[test]
+    fn rejects_truncated_signature_material() {
+        let mut encoded = encode_attachments(&[sig(0, 1)]);
+        encoded.truncate(encoded.len() - 1);
+        assert!(parse_attachments(&encoded).is_err());
+    }
+}
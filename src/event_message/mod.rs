@@ -1,21 +1,29 @@
 use crate::{
     error::Error,
-    event::Event,
-    prefix::{attached_signature::get_sig_count, AttachedSignaturePrefix, BasicPrefix, Prefix},
+    event::{event_data::EventData, Event},
+    event_message::{
+        attachment::{encode_attachments, parse_attachments},
+        serialization_format::SerializationFormat,
+        version::VersionString,
+    },
+    prefix::{AttachedSignaturePrefix, BasicPrefix, Prefix},
     state::{EventSemantics, IdentifierState, Verifiable},
     util::dfs_serializer,
 };
-use core::str::FromStr;
 use serde::{Deserialize, Serialize};
-use std::convert::TryInto;
+
+pub mod attachment;
+pub mod serialization_format;
+pub mod version;
+
+const PROTOCOL: &str = "KERI";
+const PROTOCOL_VERSION: (u8, u8) = (1, 0);
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EventMessage {
     /// Version and Size string
-    ///
-    /// TODO should be broken up into better types
     #[serde(rename = "vs")]
-    version: String,
+    version: VersionString,
 
     #[serde(flatten)]
     pub event: Event,
@@ -29,22 +37,27 @@ pub struct EventMessage {
 }
 
 impl EventMessage {
-    pub fn new(event: &Event, sigs: Vec<AttachedSignaturePrefix>) -> Result<Self, Error> {
+    pub fn new(
+        event: &Event,
+        format: SerializationFormat,
+        sigs: Vec<AttachedSignaturePrefix>,
+    ) -> Result<Self, Error> {
+        let size = Self::get_size(event, format)?;
         Ok(Self {
-            version: format!("KERI10JSON{:06x}_", event.get_serialized_size()?),
+            version: VersionString::new(PROTOCOL, PROTOCOL_VERSION, format, size),
             event: event.clone(),
             signatures: sigs,
         })
     }
 
-    pub fn get_size(event: &Event) -> Result<usize, Error> {
-        Ok(serde_json::to_string(&Self {
-            version: "KERI10JSON000000_".to_string(),
-            event: event.clone(),
-            signatures: vec![],
-        })
-        .map_err(|_| Error::DeserializationError)?
-        .len())
+    pub fn get_size(event: &Event, format: SerializationFormat) -> Result<usize, Error> {
+        Ok(format
+            .encode(&Self {
+                version: VersionString::new(PROTOCOL, PROTOCOL_VERSION, format, 0),
+                event: event.clone(),
+                signatures: vec![],
+            })?
+            .len())
     }
 
     /// Extract Serialized Data Set
@@ -53,6 +66,18 @@ impl EventMessage {
     pub fn extract_serialized_data_set(&self) -> Result<String, Error> {
         dfs_serializer::to_string(self)
     }
+
+    /// The serialization format this message was (or will be) framed in, as
+    /// advertised by the version string's serialization code.
+    fn format(&self) -> SerializationFormat {
+        self.version.format
+    }
+
+    /// Checks that `actual`, the number of bytes actually consumed decoding
+    /// this message's body, matches what the version string advertised.
+    fn validate_size(&self, actual: usize) -> Result<(), Error> {
+        self.version.validate_size(actual)
+    }
 }
 
 impl EventSemantics for EventMessage {
@@ -65,52 +90,82 @@ impl Verifiable for EventMessage {
     fn verify_against(&self, state: &IdentifierState) -> Result<bool, Error> {
         let serialized_data_extract = self.extract_serialized_data_set()?;
 
-        Ok(self.signatures.len() >= state.current.threshold
-            && self
-                .signatures
-                .iter()
-                .fold(Ok(true), |acc: Result<bool, Error>, sig| {
-                    Ok(acc?
-                        && state
-                            .current
-                            .signers
-                            .get(sig.index as usize)
-                            .ok_or(Error::SemanticError("Key not present in state".to_string()))
-                            .and_then(|key: &BasicPrefix| {
-                                key.verify(serialized_data_extract.as_bytes(), &sig.sig)
-                            })?)
-                })?)
-    }
-}
+        // An inception event establishes its own signers, so it verifies
+        // against the key config it declares rather than `state`'s (which,
+        // for the event that starts a KEL, has no signers yet).
+        let key_config = match &self.event.event_data {
+            EventData::Icp(icp) => &icp.key_config,
+            _ => &state.current,
+        };
 
-const JSON_SIG_DELIMITER: &str = "\n";
+        // Indices of the current signers whose attached signature verified.
+        let valid_indices = self
+            .signatures
+            .iter()
+            .map(|sig| {
+                key_config
+                    .signers
+                    .get(sig.index as usize)
+                    .ok_or(Error::SemanticError("Key not present in state".to_string()))
+                    .and_then(|key: &BasicPrefix| {
+                        Ok(key
+                            .verify(serialized_data_extract.as_bytes(), &sig.sig)?
+                            .then_some(sig.index as usize))
+                    })
+            })
+            .collect::<Result<Vec<Option<usize>>, Error>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<usize>>();
 
-pub fn parse_signed_message_json(message: &str) -> Result<EventMessage, Error> {
-    let parts: Vec<&str> = message.split(JSON_SIG_DELIMITER).collect();
+        key_config.threshold.is_satisfied_by(&valid_indices)
+    }
+}
 
-    let sigs: Vec<AttachedSignaturePrefix> = parts[1..]
-        .iter()
-        .map(|sig| AttachedSignaturePrefix::from_str(sig))
-        .collect::<Result<Vec<AttachedSignaturePrefix>, Error>>()?;
+/// Splits a raw message into the deserialized event body and the remaining
+/// (attachment) tail, using each format's own streaming deserializer to find
+/// the byte at which the body ends. This works uniformly across JSON, CBOR
+/// and MessagePack bodies, unlike scanning for a text delimiter.
+fn split_body(message: &[u8], format: SerializationFormat) -> Result<(EventMessage, &[u8]), Error> {
+    let (event, offset): (EventMessage, usize) = match format {
+        SerializationFormat::JSON => {
+            let mut stream = serde_json::Deserializer::from_slice(message).into_iter();
+            let event = stream.next().ok_or(Error::DeserializationError)??;
+            (event, stream.byte_offset())
+        }
+        SerializationFormat::CBOR => {
+            let mut de = serde_cbor::Deserializer::from_slice(message);
+            let event = EventMessage::deserialize(&mut de)?;
+            (event, de.byte_offset())
+        }
+        SerializationFormat::MGPK => {
+            let cursor = std::io::Cursor::new(message);
+            let mut de = rmp_serde::Deserializer::new(cursor);
+            let event = EventMessage::deserialize(&mut de)?;
+            let offset = de.get_ref().position() as usize;
+            (event, offset)
+        }
+    };
+    event.validate_size(offset)?;
+    Ok((event, &message[offset..]))
+}
 
-    Ok(EventMessage {
-        signatures: sigs,
-        ..serde_json::from_str(parts[0])?
-    })
+/// Parses a signed message in any of the supported serialization formats,
+/// detecting the format from its leading bytes and handing the bytes
+/// trailing the event body to the attachment codec for its signatures.
+pub fn parse_signed_message(message: &[u8]) -> Result<EventMessage, Error> {
+    let format = SerializationFormat::detect(message)?;
+    let (event, tail) = split_body(message, format)?;
+    let (signatures, _rest) = parse_attachments(tail)?;
+    Ok(EventMessage { signatures, ..event })
 }
 
-pub fn serialize_signed_message_json(message: &EventMessage) -> Result<String, Error> {
-    Ok([
-        serde_json::to_string(message)?,
-        get_sig_count(message.signatures.len().try_into().unwrap()),
-        message
-            .signatures
-            .iter()
-            .map(|sig| sig.to_str())
-            .collect::<Vec<String>>()
-            .join(JSON_SIG_DELIMITER),
-    ]
-    .join(JSON_SIG_DELIMITER))
+/// Serializes a signed message using the format advertised by its own
+/// version string, appending its signatures via the attachment codec.
+pub fn serialize_signed_message(message: &EventMessage) -> Result<Vec<u8>, Error> {
+    let mut out = message.format().encode(message)?;
+    out.extend(encode_attachments(&message.signatures));
+    Ok(out)
 }
 
 pub fn validate_events(kel: &[EventMessage]) -> Result<IdentifierState, Error> {
@@ -129,6 +184,7 @@ mod tests {
             event_data::{inception::InceptionEvent, EventData},
             sections::InceptionWitnessConfig,
             sections::KeyConfig,
+            sections::threshold::SigningThreshold,
         },
         prefix::{
             AttachedSignaturePrefix, BasicPrefix, IdentifierPrefix, SelfAddressingPrefix,
@@ -163,8 +219,8 @@ mod tests {
             sn: 0,
             event_data: EventData::Icp(InceptionEvent {
                 key_config: KeyConfig {
-                    threshold: 1,
-                    public_keys: vec![pref0.clone()],
+                    threshold: SigningThreshold::Unweighted(1),
+                    signers: vec![pref0.clone()],
                     threshold_key_digest: pref1.clone(),
                 },
                 witness_config: InceptionWitnessConfig::default(),
@@ -201,7 +257,7 @@ mod tests {
         assert_eq!(s0.last, SelfAddressingPrefix::default());
         assert_eq!(s0.current.signers.len(), 1);
         assert_eq!(s0.current.signers[0], pref0);
-        assert_eq!(s0.current.threshold, 1);
+        assert_eq!(s0.current.threshold, SigningThreshold::Unweighted(1));
         assert_eq!(s0.next, pref1);
         assert_eq!(s0.witnesses, vec![]);
         assert_eq!(s0.tally, 0);
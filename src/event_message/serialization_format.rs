@@ -0,0 +1,160 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+
+/// The serialization encoding carried by the four-character serialization
+/// code of a KERI version string (e.g. `KERI10JSON000000_`).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationFormat {
+    #[serde(rename = "JSON")]
+    JSON,
+    #[serde(rename = "CBOR")]
+    CBOR,
+    #[serde(rename = "MGPK")]
+    MGPK,
+}
+
+impl SerializationFormat {
+    /// The four-character code used in the version string.
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::JSON => "JSON",
+            Self::CBOR => "CBOR",
+            Self::MGPK => "MGPK",
+        }
+    }
+
+    /// Serializes `data` using this format.
+    pub fn encode<T: Serialize>(&self, data: &T) -> Result<Vec<u8>, Error> {
+        Ok(match self {
+            Self::JSON => serde_json::to_vec(data)?,
+            Self::CBOR => serde_cbor::to_vec(data)?,
+            Self::MGPK => rmp_serde::to_vec_named(data)?,
+        })
+    }
+
+    /// Deserializes a value of this format out of `bytes`.
+    pub fn decode<T: for<'de> Deserialize<'de>>(&self, bytes: &[u8]) -> Result<T, Error> {
+        Ok(match self {
+            Self::JSON => serde_json::from_slice(bytes)?,
+            Self::CBOR => serde_cbor::from_slice(bytes)?,
+            Self::MGPK => rmp_serde::from_slice(bytes)?,
+        })
+    }
+
+    /// Detects the serialization format from the leading bytes of a raw
+    /// message by sniffing the first non-whitespace byte: `{` for JSON, a
+    /// CBOR map major-type byte (`0xa0`-`0xbf`) for CBOR, or a MessagePack
+    /// map header for MGPK -- the fixmap byte (`0x80`-`0x8f`) for up to 15
+    /// fields, or the `map16`/`map32` markers (`0xde`/`0xdf`) for wider
+    /// events (e.g. rotation events with witness/threshold sections) whose
+    /// flattened field count exceeds a fixmap's 15-entry limit.
+    pub fn detect(raw: &[u8]) -> Result<Self, Error> {
+        match raw.iter().find(|b| !b.is_ascii_whitespace()).copied() {
+            Some(b'{') => Ok(Self::JSON),
+            Some(0xa0..=0xbf) => Ok(Self::CBOR),
+            Some(0x80..=0x8f) | Some(0xde) | Some(0xdf) => Ok(Self::MGPK),
+            _ => Err(Error::DeserializationError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct Sample {
+        a: u32,
+        b: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct WideSample {
+        f0: u8,
+        f1: u8,
+        f2: u8,
+        f3: u8,
+        f4: u8,
+        f5: u8,
+        f6: u8,
+        f7: u8,
+        f8: u8,
+        f9: u8,
+        f10: u8,
+        f11: u8,
+        f12: u8,
+        f13: u8,
+        f14: u8,
+        f15: u8,
+        f16: u8,
+    }
+
+    #[test]
+    fn round_trips_through_every_format() {
+        let sample = Sample {
+            a: 7,
+            b: "hi".to_string(),
+        };
+        for format in [
+            SerializationFormat::JSON,
+            SerializationFormat::CBOR,
+            SerializationFormat::MGPK,
+        ] {
+            let encoded = format.encode(&sample).unwrap();
+            let decoded: Sample = format.decode(&encoded).unwrap();
+            assert_eq!(decoded, sample);
+        }
+    }
+
+    #[test]
+    fn detects_format_from_leading_bytes() {
+        let sample = Sample {
+            a: 1,
+            b: "x".to_string(),
+        };
+        assert_eq!(
+            SerializationFormat::detect(&SerializationFormat::JSON.encode(&sample).unwrap())
+                .unwrap(),
+            SerializationFormat::JSON
+        );
+        assert_eq!(
+            SerializationFormat::detect(&SerializationFormat::CBOR.encode(&sample).unwrap())
+                .unwrap(),
+            SerializationFormat::CBOR
+        );
+        assert_eq!(
+            SerializationFormat::detect(&SerializationFormat::MGPK.encode(&sample).unwrap())
+                .unwrap(),
+            SerializationFormat::MGPK
+        );
+    }
+
+    #[test]
+    fn detects_msgpack_map16_header_for_wide_structs() {
+        // More than 15 fields forces rmp_serde to emit a map16 (0xde)
+        // header instead of a fixmap -- detection must still recognize it.
+        let wide = WideSample {
+            f0: 0,
+            f1: 0,
+            f2: 0,
+            f3: 0,
+            f4: 0,
+            f5: 0,
+            f6: 0,
+            f7: 0,
+            f8: 0,
+            f9: 0,
+            f10: 0,
+            f11: 0,
+            f12: 0,
+            f13: 0,
+            f14: 0,
+            f15: 0,
+            f16: 0,
+        };
+        let encoded = SerializationFormat::MGPK.encode(&wide).unwrap();
+        assert_eq!(encoded[0], 0xde);
+        assert_eq!(SerializationFormat::detect(&encoded).unwrap(), SerializationFormat::MGPK);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{error::Error, event_message::serialization_format::SerializationFormat};
+use core::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Length in bytes of a serialized version string, e.g. `KERI10JSON000000_`:
+/// 4-char protocol, 2-hex version, 4-char serialization code, 6-hex size,
+/// 1-char terminator.
+const VERSION_STRING_LENGTH: usize = 4 + 2 + 4 + 6 + 1;
+
+/// A parsed KERI version string: `{protocol}{major}{minor}{format}{size}_`.
+///
+/// Replaces the raw `vs` string field on [`super::EventMessage`] with a
+/// structured handle that can be validated and inspected without
+/// re-parsing, and that round-trips through [`FromStr`]/[`Display`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionString {
+    pub protocol: String,
+    pub version: (u8, u8),
+    pub format: SerializationFormat,
+    pub size: usize,
+}
+
+impl VersionString {
+    pub fn new(protocol: &str, version: (u8, u8), format: SerializationFormat, size: usize) -> Self {
+        Self {
+            protocol: protocol.to_string(),
+            version,
+            format,
+            size,
+        }
+    }
+
+    /// Returns an error unless `actual` matches the size this version
+    /// string advertises.
+    pub fn validate_size(&self, actual: usize) -> Result<(), Error> {
+        if self.size != actual {
+            Err(Error::IncorrectSize {
+                expected: self.size,
+                actual,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl FromStr for VersionString {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.len() < VERSION_STRING_LENGTH {
+            return Err(Error::VersionParsingError(s.to_string()));
+        }
+        let protocol = &s[0..4];
+        let major = u8::from_str_radix(&s[4..5], 16)
+            .map_err(|_| Error::VersionParsingError(s.to_string()))?;
+        let minor = u8::from_str_radix(&s[5..6], 16)
+            .map_err(|_| Error::VersionParsingError(s.to_string()))?;
+        let format = match &s[6..10] {
+            "JSON" => SerializationFormat::JSON,
+            "CBOR" => SerializationFormat::CBOR,
+            "MGPK" => SerializationFormat::MGPK,
+            _ => return Err(Error::VersionParsingError(s.to_string())),
+        };
+        let size = usize::from_str_radix(&s[10..16], 16)
+            .map_err(|_| Error::VersionParsingError(s.to_string()))?;
+        if &s[16..17] != "_" {
+            return Err(Error::VersionParsingError(s.to_string()));
+        }
+        Ok(Self::new(protocol, (major, minor), format, size))
+    }
+}
+
+impl fmt::Display for VersionString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{:x}{:x}{}{:06x}_",
+            self.protocol,
+            self.version.0,
+            self.version.1,
+            self.format.to_str(),
+            self.size
+        )
+    }
+}
+
+impl Serialize for VersionString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        VersionString::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_display_and_from_str() {
+        let vs = VersionString::new("KERI", (1, 0), SerializationFormat::JSON, 0x123);
+        let rendered = vs.to_string();
+        assert_eq!(rendered, "KERI10JSON000123_");
+        assert_eq!(VersionString::from_str(&rendered).unwrap(), vs);
+    }
+
+    #[test]
+    fn parses_every_serialization_code() {
+        assert_eq!(
+            VersionString::from_str("KERI10JSON000000_").unwrap().format,
+            SerializationFormat::JSON
+        );
+        assert_eq!(
+            VersionString::from_str("KERI10CBOR000000_").unwrap().format,
+            SerializationFormat::CBOR
+        );
+        assert_eq!(
+            VersionString::from_str("KERI10MGPK000000_").unwrap().format,
+            SerializationFormat::MGPK
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        assert!(VersionString::from_str("too_short").is_err());
+        assert!(VersionString::from_str("KERI10WXYZ000000_").is_err());
+        assert!(VersionString::from_str("KERI10JSON000000X").is_err());
+    }
+
+    #[test]
+    fn validates_advertised_size() {
+        let vs = VersionString::new("KERI", (1, 0), SerializationFormat::JSON, 10);
+        assert!(vs.validate_size(10).is_ok());
+        assert!(matches!(
+            vs.validate_size(11),
+            Err(Error::IncorrectSize {
+                expected: 10,
+                actual: 11
+            })
+        ));
+    }
+}
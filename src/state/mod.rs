@@ -0,0 +1,48 @@
+use crate::{
+    error::Error,
+    event::sections::KeyConfig,
+    prefix::{BasicPrefix, IdentifierPrefix, SelfAddressingPrefix},
+};
+use serde::{Deserialize, Serialize};
+
+/// Applies an event to the identifier state it follows, producing the
+/// state that comes after it.
+pub trait EventSemantics {
+    fn apply_to(&self, state: IdentifierState) -> Result<IdentifierState, Error>;
+}
+
+/// Checks whether an event is validly signed against a given identifier
+/// state, i.e. that enough of its current signers attached a verifying
+/// signature to meet `state.current.threshold`. An implementor may check an
+/// event that declares its own key configuration (such as an inception
+/// event) against that configuration instead.
+pub trait Verifiable {
+    fn verify_against(&self, state: &IdentifierState) -> Result<bool, Error>;
+}
+
+/// A snapshot of an identifier's key state at a point in its KEL.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct IdentifierState {
+    pub prefix: IdentifierPrefix,
+    pub sn: u64,
+    pub last: SelfAddressingPrefix,
+    pub current: KeyConfig,
+    pub next: SelfAddressingPrefix,
+    pub witnesses: Vec<BasicPrefix>,
+    pub tally: u64,
+    pub delegated_keys: Vec<IdentifierPrefix>,
+}
+
+impl IdentifierState {
+    /// Verifies `event` against this state and, if its signatures satisfy
+    /// the current threshold, applies it to produce the next state.
+    pub fn verify_and_apply<E: EventSemantics + Verifiable>(self, event: &E) -> Result<Self, Error> {
+        if event.verify_against(&self)? {
+            event.apply_to(self)
+        } else {
+            Err(Error::SemanticError(
+                "Signing threshold not met".to_string(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Thin WASM/FFI bindings over the core KERI types.
+//!
+//! This module only translates between the signed-message wire form and
+//! the pure-Rust types in [`crate::event_message`], [`crate::event`] and
+//! [`crate::state`] — the core crate itself is untouched. Every entry
+//! point accepts/returns plain strings (the signed-message string form) or
+//! JSON-serializable records, and surfaces [`Error`] as a JS exception
+//! rather than a Rust `Result` the caller can't inspect.
+#![cfg(feature = "wasm")]
+
+use crate::{
+    error::Error,
+    event::Event,
+    event_message::{
+        parse_signed_message, serialization_format::SerializationFormat,
+        serialize_signed_message, validate_events, EventMessage,
+    },
+    prefix::{AttachedSignaturePrefix, Prefix},
+    state::IdentifierState,
+};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+impl From<Error> for JsValue {
+    fn from(err: Error) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// A JS-friendly snapshot of an [`IdentifierState`], returned as the result
+/// of replaying a KEL. Not meant to be fed back in as input: a caller that
+/// wants to extend a KEL should keep accumulating wire-form messages and
+/// re-validate with [`validate_kel`], not round-trip this summary.
+#[derive(Serialize, Deserialize)]
+pub struct IdentifierStateSummary {
+    pub prefix: String,
+    pub sn: u64,
+    pub current_keys: Vec<String>,
+    pub next_digest: String,
+}
+
+impl From<&IdentifierState> for IdentifierStateSummary {
+    fn from(state: &IdentifierState) -> Self {
+        Self {
+            prefix: state.prefix.to_str(),
+            sn: state.sn,
+            current_keys: state.current.signers.iter().map(|k| k.to_str()).collect(),
+            next_digest: state.next.to_str(),
+        }
+    }
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(value).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn from_js<T: for<'de> Deserialize<'de>>(value: JsValue) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn parse_kel(signed_messages: &[String]) -> Result<Vec<EventMessage>, Error> {
+    signed_messages
+        .iter()
+        .map(|message| parse_signed_message(message.as_bytes()))
+        .collect()
+}
+
+/// A JS-friendly record of an unsigned, framed event: its wire form (with
+/// no signatures attached yet) alongside the exact bytes a caller must sign
+/// to produce a valid [`AttachedSignaturePrefix`] for it.
+#[derive(Serialize)]
+pub struct UnsignedEventMessage {
+    pub message: String,
+    pub data_to_sign: String,
+}
+
+/// Builds an inception (or other) event from its JS representation and
+/// frames it as an `EventMessage` with no signatures attached, returning
+/// both its wire form and the data a caller must sign over.
+#[wasm_bindgen(js_name = newEventMessage)]
+pub fn new_event_message(event: JsValue) -> Result<JsValue, JsValue> {
+    let event: Event = from_js(event)?;
+    let message = EventMessage::new(&event, SerializationFormat::JSON, vec![])?;
+    let data_to_sign = message.extract_serialized_data_set()?;
+    let bytes = serialize_signed_message(&message)?;
+    let wire = String::from_utf8(bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_js(&UnsignedEventMessage {
+        message: wire,
+        data_to_sign,
+    })
+}
+
+/// Signs `event` with `signatures`, mirroring `Event::sign`, and returns the
+/// resulting signed-message wire form.
+#[wasm_bindgen(js_name = signEvent)]
+pub fn sign_event(event: JsValue, signatures: JsValue) -> Result<String, JsValue> {
+    let event: Event = from_js(event)?;
+    let signatures: Vec<AttachedSignaturePrefix> = from_js(signatures)?;
+    let signed = event.sign(signatures)?;
+    let bytes = serialize_signed_message(&signed)?;
+    String::from_utf8(bytes).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Replays a full key event log, given as an array of signed-message wire
+/// strings, from an empty `IdentifierState`, and returns the resulting
+/// state as a JS-serializable summary. A caller extending an existing KEL
+/// by one event should pass the whole prior array plus the new message,
+/// rather than trying to resume from a previously-returned summary.
+#[wasm_bindgen(js_name = validateKel)]
+pub fn validate_kel(signed_messages: JsValue) -> Result<JsValue, JsValue> {
+    let signed_messages: Vec<String> = from_js(signed_messages)?;
+    let kel = parse_kel(&signed_messages)?;
+    let state = validate_events(&kel)?;
+    to_js(&IdentifierStateSummary::from(&state))
+}